@@ -0,0 +1,101 @@
+//! Attribute macro that turns a constructor-style function into an
+//! auto-wired provider function for `dependency_provider::DependencyProvider`.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, FnArg, ItemFn, ReturnType};
+
+/// Generate an auto-wired provider from a constructor function.
+///
+/// Annotating `fn build(cfg: Config, db: Db) -> Service { .. }` with
+/// `#[provides]` keeps the original function and additionally emits
+/// `build_provider`, a function of shape `Fn(&DependencyProvider) -> Service`
+/// that resolves each parameter type via `DependencyProvider::get` before
+/// calling `build`, plus `register_build`, a one-line helper that registers
+/// `build_provider` on a `DependencyProvider` via `register_wired`.
+///
+/// # Examples
+///
+/// ```
+/// use dependency_provider::DependencyProvider;
+/// use dependency_provider_macros::provides;
+///
+/// #[derive(Default)]
+/// struct Config(i32);
+/// #[derive(Default)]
+/// struct Db(i32);
+/// struct Service(i32);
+///
+/// #[provides]
+/// fn build(cfg: Config, db: Db) -> Service {
+///     Service(cfg.0 + db.0)
+/// }
+///
+/// let provider = DependencyProvider::new()
+///     .register(|| Config::default())
+///     .register(|| Db::default());
+/// let provider = register_build(provider);
+/// assert_eq!(Some(0), provider.get::<Service>().map(|s| s.0));
+/// ```
+#[proc_macro_attribute]
+pub fn provides(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as ItemFn);
+
+    let fn_name = &input.sig.ident;
+    let fn_vis = &input.vis;
+    let output_ty = match &input.sig.output {
+        ReturnType::Type(_, ty) => ty.as_ref().clone(),
+        ReturnType::Default => {
+            return syn::Error::new_spanned(
+                &input.sig,
+                "#[provides] functions must return the dependency type",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    let mut arg_types = Vec::new();
+    for arg in &input.sig.inputs {
+        match arg {
+            FnArg::Typed(pat_type) => arg_types.push(pat_type.ty.as_ref().clone()),
+            FnArg::Receiver(receiver) => {
+                return syn::Error::new_spanned(
+                    receiver,
+                    "#[provides] does not support methods with `self`",
+                )
+                .to_compile_error()
+                .into();
+            }
+        }
+    }
+
+    let provider_fn_name = format_ident!("{}_provider", fn_name);
+    let register_fn_name = format_ident!("register_{}", fn_name);
+    let resolved_args = arg_types.iter().map(|ty| {
+        quote! {
+            p.get::<#ty>().expect(concat!(
+                "no provider registered for a dependency of #[provides] fn ",
+                stringify!(#fn_name),
+            ))
+        }
+    });
+
+    let expanded = quote! {
+        #input
+
+        #fn_vis fn #provider_fn_name(
+            p: &::dependency_provider::DependencyProvider,
+        ) -> #output_ty {
+            #fn_name(#(#resolved_args),*)
+        }
+
+        #fn_vis fn #register_fn_name(
+            provider: ::dependency_provider::DependencyProvider,
+        ) -> ::dependency_provider::DependencyProvider {
+            provider.register_wired(#provider_fn_name)
+        }
+    };
+
+    expanded.into()
+}
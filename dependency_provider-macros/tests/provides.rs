@@ -0,0 +1,33 @@
+use dependency_provider::DependencyProvider;
+use dependency_provider_macros::provides;
+
+#[derive(Debug, Eq, PartialEq)]
+struct Config(i32);
+#[derive(Debug, Eq, PartialEq)]
+struct Db(i32);
+#[derive(Debug, Eq, PartialEq)]
+struct Service(i32);
+
+#[provides]
+fn build_db(cfg: Config) -> Db {
+    Db(cfg.0 + 1)
+}
+
+#[provides]
+fn build_service(db: Db) -> Service {
+    Service(db.0 + 1)
+}
+
+#[test]
+fn provides_wires_constructor_dependencies() {
+    let provider = DependencyProvider::new().register(|| Config(1));
+    let provider = register_build_db(provider);
+    let provider = register_build_service(provider);
+
+    assert_eq!(Some(Service(3)), provider.get::<Service>());
+}
+
+#[test]
+fn provides_keeps_the_original_function_callable() {
+    assert_eq!(Db(2), build_db(Config(1)));
+}